@@ -1,7 +1,7 @@
 import Foundation
 
 struct CheckUsageOutput: Decodable {
-    let claude: CLIUsageInfo
+    let claude: CLIUsageInfo?
     let codex: CLIUsageInfo?
     let gemini: CLIUsageInfo?
     let zai: CLIUsageInfo?
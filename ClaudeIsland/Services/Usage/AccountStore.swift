@@ -4,6 +4,7 @@ enum UsageService: String, Codable {
     case claude
     case codex
     case gemini
+    case zai
 }
 
 struct UsageAccount: Codable, Equatable, Identifiable {
@@ -12,11 +13,13 @@ struct UsageAccount: Codable, Equatable, Identifiable {
     let label: String
     let rootPath: String
     let updatedAt: Date
+    var scopes: [String]? = nil
 }
 
 struct AccountsSnapshot: Codable {
     var accounts: [UsageAccount]
     var profiles: [UsageProfile]
+    var schemaVersion: Int? = nil
 }
 
 final class AccountStore {
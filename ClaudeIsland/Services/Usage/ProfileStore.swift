@@ -5,6 +5,7 @@ struct UsageProfile: Codable, Equatable, Identifiable {
     let claudeAccountId: String?
     let codexAccountId: String?
     let geminiAccountId: String?
+    var zaiAccountId: String? = nil
 
     var id: String { name }
 }
@@ -56,6 +56,10 @@ enum UsageCredentialHasher {
                 return "gemini:refresh:\(refreshToken)"
             }
             return nil
+        case .zai:
+            guard let dict = root as? [String: Any] else { return nil }
+            guard let authToken = normalizedString(dict["authToken"]) else { return nil }
+            return "zai:token:\(authToken)"
         }
     }
 
@@ -251,7 +255,8 @@ final class ProfileSwitcher {
                 name: trimmed,
                 claudeAccountId: account.id,
                 codexAccountId: profile.codexAccountId,
-                geminiAccountId: profile.geminiAccountId
+                geminiAccountId: profile.geminiAccountId,
+                zaiAccountId: profile.zaiAccountId
             )
             if written { accountsWritten.append(account) }
         } else {
@@ -270,7 +275,8 @@ final class ProfileSwitcher {
                     name: trimmed,
                     claudeAccountId: profile.claudeAccountId,
                     codexAccountId: account.id,
-                    geminiAccountId: profile.geminiAccountId
+                    geminiAccountId: profile.geminiAccountId,
+                    zaiAccountId: profile.zaiAccountId
                 )
                 if written { accountsWritten.append(account) }
             } else {
@@ -291,7 +297,8 @@ final class ProfileSwitcher {
                 name: trimmed,
                 claudeAccountId: profile.claudeAccountId,
                 codexAccountId: profile.codexAccountId,
-                geminiAccountId: account.id
+                geminiAccountId: account.id,
+                zaiAccountId: profile.zaiAccountId
             )
             if written { accountsWritten.append(account) }
         } else {
@@ -536,7 +543,8 @@ final class ProfileSwitcher {
                 service: .claude,
                 label: targetLabel,
                 rootPath: targetRoot.path,
-                updatedAt: account.updatedAt
+                updatedAt: account.updatedAt,
+                scopes: account.scopes
             )
 
             if let existing = mergedClaudeByID[targetID] {
@@ -560,7 +568,8 @@ final class ProfileSwitcher {
                 name: profile.name,
                 claudeAccountId: remappedID,
                 codexAccountId: profile.codexAccountId,
-                geminiAccountId: profile.geminiAccountId
+                geminiAccountId: profile.geminiAccountId,
+                zaiAccountId: profile.zaiAccountId
             )
         }
 
@@ -756,6 +765,8 @@ final class ProfileSwitcher {
             exportCredentials = ExportCredentials(claude: nil, codex: data, gemini: nil)
         case .gemini:
             exportCredentials = ExportCredentials(claude: nil, codex: nil, gemini: data)
+        case .zai:
+            exportCredentials = ExportCredentials(claude: nil, codex: nil, gemini: nil)
         }
 
         let root = accountsDir.appendingPathComponent(accountId, isDirectory: true)
@@ -766,7 +777,8 @@ final class ProfileSwitcher {
             service: service,
             label: "\(service.rawValue):\(hashPrefix)",
             rootPath: root.path,
-            updatedAt: updatedAt
+            updatedAt: updatedAt,
+            scopes: snapshot.accounts.first(where: { $0.id == accountId })?.scopes
         )
         upsertAccount(account, snapshot: &snapshot)
 
@@ -294,6 +294,8 @@ final class UsageFetcher {
             return ".codex/auth.json"
         case .gemini:
             return ".gemini/oauth_creds.json"
+        case .zai:
+            return "zai.json"
         }
     }
 
@@ -279,6 +279,7 @@ actor UsageIdentityStore {
         if accountId.hasPrefix("acct_claude_") { return .claude }
         if accountId.hasPrefix("acct_codex_") { return .codex }
         if accountId.hasPrefix("acct_gemini_") { return .gemini }
+        if accountId.hasPrefix("acct_zai_") { return .zai }
         return nil
     }
 
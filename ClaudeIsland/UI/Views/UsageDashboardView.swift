@@ -282,7 +282,8 @@ final class UsageDashboardViewModel: ObservableObject {
                 service: account.service,
                 label: account.label,
                 rootPath: account.rootPath,
-                updatedAt: now
+                updatedAt: now,
+                scopes: account.scopes
             )
             changed = true
         }
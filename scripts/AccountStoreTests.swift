@@ -8,6 +8,31 @@ enum AccountStoreTests {
         try store.saveProfiles([profile])
         let loaded = try store.loadProfiles()
         assert(loaded.first?.name == "A")
+
+        let zaiProfile = UsageProfile(
+            name: "Z",
+            claudeAccountId: nil,
+            codexAccountId: nil,
+            geminiAccountId: nil,
+            zaiAccountId: "acct_zai_0123"
+        )
+        let zaiAccount = UsageAccount(
+            id: "acct_zai_0123",
+            service: .zai,
+            label: "zai:0123",
+            rootPath: "/tmp/claude-island-test/accounts/acct_zai_0123",
+            updatedAt: Date(timeIntervalSince1970: 1_700_000_000)
+        )
+        try store.saveSnapshot(AccountsSnapshot(accounts: [zaiAccount], profiles: [zaiProfile]))
+        let reloaded = try store.loadSnapshot()
+        assert(reloaded.accounts.first?.service == .zai)
+        assert(reloaded.profiles.first?.zaiAccountId == "acct_zai_0123")
+
+        var stamped = reloaded
+        stamped.schemaVersion = 1
+        try store.saveSnapshot(stamped)
+        try store.saveProfiles([profile])
+        assert(try store.loadSnapshot().schemaVersion == 1)
         print("OK")
     }
 }
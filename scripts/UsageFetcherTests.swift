@@ -38,9 +38,9 @@ enum UsageFetcherTests {
         """
 
         let output = try UsageFetcher.decodeUsageOutput(Data(json.utf8))
-        assert(output.claude.available)
-        assert(output.claude.fiveHourPercent == 12)
-        assert(output.claude.sevenDayPercent == 34)
+        assert(output.claude?.available == true)
+        assert(output.claude?.fiveHourPercent == 12)
+        assert(output.claude?.sevenDayPercent == 34)
         assert(output.codex == nil)
         assert(output.gemini == nil)
         assert(output.zai == nil)